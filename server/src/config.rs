@@ -0,0 +1,80 @@
+use serde::Deserialize;
+
+/// Top-level configuration, assembled from `configuration.yaml` and overlaid
+/// with environment variables prefixed `APP_` (nested keys use `__`, e.g.
+/// `APP_DATABASE__PASSWORD`).
+#[derive(Debug, Clone, Deserialize)]
+pub struct Settings {
+    pub application: ApplicationSettings,
+    pub database: DatabaseSettings,
+    pub monitor: MonitorSettings,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MonitorSettings {
+    /// Whether the background health sweep runs in this environment.
+    pub enabled: bool,
+    /// Seconds to wait between sweep passes.
+    pub interval_seconds: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApplicationSettings {
+    pub host: String,
+    pub port: u16,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DatabaseSettings {
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub password: String,
+    pub database_name: String,
+    pub require_ssl: bool,
+    /// Upper bound on pooled connections.
+    pub max_connections: u32,
+    /// Connections to keep warm even when idle.
+    pub min_connections: u32,
+    /// Seconds to wait for a free connection before erroring.
+    pub acquire_timeout_seconds: u64,
+    /// Seconds an idle connection lives before being reaped.
+    pub idle_timeout_seconds: u64,
+    /// Seconds to wait for the initial TCP/handshake before failing fast.
+    pub connect_timeout_seconds: u64,
+}
+
+impl DatabaseSettings {
+    /// Build the Postgres DSN for the configured database.
+    pub fn connection_string(&self) -> String {
+        let ssl_mode = if self.require_ssl {
+            "require"
+        } else {
+            "prefer"
+        };
+        format!(
+            "postgres://{}:{}@{}:{}/{}?sslmode={}",
+            self.username,
+            self.password,
+            self.host,
+            self.port,
+            self.database_name,
+            ssl_mode,
+        )
+    }
+}
+
+/// Load the layered configuration: `configuration.yaml` first, then any
+/// `APP_*` environment overrides.
+pub fn get_configuration() -> Result<Settings, config::ConfigError> {
+    let settings = config::Config::builder()
+        .add_source(config::File::with_name("configuration").required(false))
+        .add_source(
+            config::Environment::with_prefix("APP")
+                .prefix_separator("_")
+                .separator("__"),
+        )
+        .build()?;
+
+    settings.try_deserialize::<Settings>()
+}
@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+use sqlx::{Pool, Postgres};
+
+use crate::config::MonitorSettings;
+use crate::job::TargetStatus;
+use crate::repository::MonitoredTargetRepository;
+
+/// Spawn the periodic health sweep as a detached background task.
+///
+/// The worker sleeps for `settings.interval_seconds` between passes, checks
+/// every monitored target, persists the result, and mirrors the up/down state
+/// into `status` so the HTTP handlers can read it without hitting the DB.
+/// Does nothing when the sweep is disabled for the environment.
+pub fn spawn(pool: Pool<Postgres>, settings: MonitorSettings, status: TargetStatus) {
+    if !settings.enabled {
+        return;
+    }
+
+    tokio::spawn(async move {
+        let targets = MonitoredTargetRepository::new(pool);
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(5))
+            .build()
+            .expect("Failed to build HTTP client for health sweep");
+        let interval = Duration::from_secs(settings.interval_seconds);
+
+        loop {
+            match targets.list().await {
+                Ok(list) => {
+                    for target in list {
+                        let is_up = client
+                            .get(&target.url)
+                            .send()
+                            .await
+                            .map(|resp| resp.status().is_success())
+                            .unwrap_or(false);
+
+                        if let Err(e) = targets.record_status(target.id, is_up).await {
+                            eprintln!("health sweep: failed to record status for {}: {e}", target.id);
+                        }
+
+                        if let Ok(mut map) = status.lock() {
+                            map.insert(target.id, is_up);
+                        }
+                    }
+                }
+                Err(e) => eprintln!("health sweep: failed to list targets: {e}"),
+            }
+
+            tokio::time::sleep(interval).await;
+        }
+    });
+}
@@ -0,0 +1,13 @@
+pub mod health_sweep;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Shared per-target up/down state, keyed by target id. Written by the health
+/// sweep worker and read by the HTTP handlers.
+pub type TargetStatus = Arc<Mutex<HashMap<i32, bool>>>;
+
+/// Create an empty shared target-status map.
+pub fn new_target_status() -> TargetStatus {
+    Arc::new(Mutex::new(HashMap::new()))
+}
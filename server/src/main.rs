@@ -1,22 +1,52 @@
+mod config;
+mod job;
 mod model;
+mod repository;
 mod router;
+mod telemetry;
 
-use actix_web::{App, HttpServer};
+use actix_web::{App, HttpServer, middleware::Logger, web};
+use config::get_configuration;
 use model::db::init_db;
+use repository::UserRepository;
+use tracing_actix_web::TracingLogger;
 
 #[tokio::main]
 async fn main() -> std::io::Result<()> {
     dotenvy::dotenv().ok();
+    telemetry::init_tracing();
+
+    let settings = get_configuration().expect("Failed to read configuration");
 
     // initialize db connection pool
-    let pool = init_db().await.expect("Failed to connect to DB");
+    let pool = init_db(&settings.database)
+        .await
+        .expect("Failed to connect to DB");
+    let pool = web::Data::new(pool);
+    let user_repository = web::Data::new(UserRepository::new(pool.get_ref().clone()));
+
+    // Shared up/down state, populated by the background health sweep and read
+    // back by the HTTP handlers.
+    let target_status = job::new_target_status();
+    job::health_sweep::spawn(
+        pool.get_ref().clone(),
+        settings.monitor.clone(),
+        target_status.clone(),
+    );
+    let target_status = web::Data::new(target_status);
+
+    let bind_address = (settings.application.host.clone(), settings.application.port);
 
     HttpServer::new(move || {
         App::new()
-            .app_data(pool.clone()) // share pool with routes
+            .wrap(TracingLogger::default()) // per-request id span
+            .wrap(Logger::default()) // access log
+            .app_data(pool.clone()) // raw pool for infra-level health probes
+            .app_data(user_repository.clone()) // domain handlers depend on repositories
+            .app_data(target_status.clone()) // shared health-sweep state
             .configure(router::init)
     })
-    .bind(("127.0.0.1", 8080))?
+    .bind(bind_address)?
     .run()
     .await
 }
@@ -1,8 +1,33 @@
+use std::time::Duration;
+
+use sqlx::postgres::PgPoolOptions;
 use sqlx::{Pool, Postgres};
 
-pub async fn init_db() -> Result<Pool<Postgres>, sqlx::Error> {
-    let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set in .env");
+use crate::config::DatabaseSettings;
+
+#[tracing::instrument(name = "init_db", skip(settings))]
+pub async fn init_db(settings: &DatabaseSettings) -> Result<Pool<Postgres>, sqlx::Error> {
+    let pool_fut = PgPoolOptions::new()
+        .max_connections(settings.max_connections)
+        .min_connections(settings.min_connections)
+        .acquire_timeout(Duration::from_secs(settings.acquire_timeout_seconds))
+        .idle_timeout(Duration::from_secs(settings.idle_timeout_seconds))
+        .connect(&settings.connection_string());
+
+    // Bound the initial connect so a slow/unreachable DB fails startup fast
+    // instead of blocking the process indefinitely.
+    let connect_timeout = Duration::from_secs(settings.connect_timeout_seconds);
+    let pool = match tokio::time::timeout(connect_timeout, pool_fut).await {
+        Ok(result) => result?,
+        Err(_) => return Err(sqlx::Error::PoolTimedOut),
+    };
+
+    // Bring the schema up to date on boot so fresh deployments and CI runs
+    // come up correct without a manual `sqlx migrate run`.
+    sqlx::migrate!("./migrations")
+        .run(&pool)
+        .await
+        .map_err(|e| sqlx::Error::Migrate(Box::new(e)))?;
 
-    let pool = Pool::<Postgres>::connect(&database_url).await?;
     Ok(pool)
 }
@@ -0,0 +1,3 @@
+pub mod db;
+pub mod target;
+pub mod user;
@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// A target polled by the background health sweep.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct MonitoredTarget {
+    pub id: i32,
+    pub name: String,
+    pub url: String,
+    pub is_up: bool,
+    pub checked_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A registered user of the service.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct User {
+    pub id: i32,
+    pub email: String,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Fields accepted when creating a new user.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NewUser {
+    pub email: String,
+    pub name: String,
+}
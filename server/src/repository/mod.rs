@@ -0,0 +1,5 @@
+pub mod target;
+pub mod user;
+
+pub use target::MonitoredTargetRepository;
+pub use user::UserRepository;
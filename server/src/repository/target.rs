@@ -0,0 +1,33 @@
+use sqlx::{Pool, Postgres};
+
+use crate::model::target::MonitoredTarget;
+
+/// Persistence gateway for the targets watched by the health sweep.
+#[derive(Clone)]
+pub struct MonitoredTargetRepository {
+    pool: Pool<Postgres>,
+}
+
+impl MonitoredTargetRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list(&self) -> Result<Vec<MonitoredTarget>, sqlx::Error> {
+        sqlx::query_as::<_, MonitoredTarget>(
+            "SELECT id, name, url, is_up, checked_at FROM monitored_targets ORDER BY id",
+        )
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    /// Record the latest up/down result for a target.
+    pub async fn record_status(&self, id: i32, is_up: bool) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE monitored_targets SET is_up = $1, checked_at = now() WHERE id = $2")
+            .bind(is_up)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
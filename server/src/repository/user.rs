@@ -0,0 +1,44 @@
+use sqlx::{Pool, Postgres};
+
+use crate::model::user::{NewUser, User};
+
+/// Single gatekeeper for all user persistence. Handlers depend on this rather
+/// than touching `sqlx` so storage concerns stay in one place.
+#[derive(Clone)]
+pub struct UserRepository {
+    pool: Pool<Postgres>,
+}
+
+impl UserRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create(&self, new_user: &NewUser) -> Result<User, sqlx::Error> {
+        sqlx::query_as::<_, User>(
+            "INSERT INTO users (email, name) VALUES ($1, $2) \
+             RETURNING id, email, name, created_at",
+        )
+        .bind(&new_user.email)
+        .bind(&new_user.name)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn find_by_id(&self, id: i32) -> Result<Option<User>, sqlx::Error> {
+        sqlx::query_as::<_, User>(
+            "SELECT id, email, name, created_at FROM users WHERE id = $1",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn list(&self) -> Result<Vec<User>, sqlx::Error> {
+        sqlx::query_as::<_, User>(
+            "SELECT id, email, name, created_at FROM users ORDER BY id",
+        )
+        .fetch_all(&self.pool)
+        .await
+    }
+}
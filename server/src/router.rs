@@ -1,10 +1,88 @@
-use actix_web::{HttpResponse, Responder, get, web};
+use std::time::Duration;
+
+use actix_web::{HttpResponse, Responder, get, post, web};
+use serde_json::json;
+use sqlx::{Pool, Postgres};
+
+use crate::job::TargetStatus;
+use crate::model::user::NewUser;
+use crate::repository::UserRepository;
 
 #[get("/healthCheck")]
 async fn health_check() -> impl Responder {
     HttpResponse::Ok().body("Server is working!")
 }
 
+/// Liveness probe: returns 200 as long as the process is running.
+///
+/// kubelet uses this to decide whether the container needs a restart, so it
+/// must not depend on any downstream such as the database.
+#[get("/health/live")]
+async fn health_live() -> impl Responder {
+    HttpResponse::Ok().json(json!({ "status": "healthy" }))
+}
+
+/// Readiness probe: returns 200 only when the service can reach Postgres.
+///
+/// Runs a cheap `SELECT 1` with a short timeout so kubelet stops routing
+/// traffic to the pod during a DB outage instead of serving failing requests.
+#[get("/health/ready")]
+#[tracing::instrument(name = "health_ready", skip(pool))]
+async fn health_ready(pool: web::Data<Pool<Postgres>>) -> impl Responder {
+    let probe = sqlx::query("SELECT 1").execute(pool.get_ref());
+
+    match tokio::time::timeout(Duration::from_secs(2), probe).await {
+        Ok(Ok(_)) => HttpResponse::Ok().json(json!({ "status": "healthy" })),
+        Ok(Err(e)) => HttpResponse::ServiceUnavailable()
+            .json(json!({ "status": "unhealthy", "reason": e.to_string() })),
+        Err(_) => HttpResponse::ServiceUnavailable()
+            .json(json!({ "status": "unhealthy", "reason": "database probe timed out" })),
+    }
+}
+
+#[get("/users")]
+#[tracing::instrument(name = "list_users", skip(users))]
+async fn list_users(users: web::Data<UserRepository>) -> impl Responder {
+    match users.list().await {
+        Ok(users) => HttpResponse::Ok().json(users),
+        Err(e) => HttpResponse::InternalServerError()
+            .json(json!({ "error": e.to_string() })),
+    }
+}
+
+#[post("/users")]
+#[tracing::instrument(name = "create_user", skip(users, body))]
+async fn create_user(
+    users: web::Data<UserRepository>,
+    body: web::Json<NewUser>,
+) -> impl Responder {
+    match users.create(&body).await {
+        Ok(user) => HttpResponse::Created().json(user),
+        Err(e) => HttpResponse::InternalServerError()
+            .json(json!({ "error": e.to_string() })),
+    }
+}
+
+/// Report the last-known up/down state of every monitored target, as gathered
+/// by the background health sweep.
+#[get("/monitor/status")]
+async fn monitor_status(status: web::Data<TargetStatus>) -> impl Responder {
+    let snapshot: std::collections::HashMap<i32, bool> = match status.lock() {
+        Ok(map) => map.clone(),
+        Err(_) => return HttpResponse::InternalServerError()
+            .json(json!({ "error": "status state poisoned" })),
+    };
+    HttpResponse::Ok().json(snapshot)
+}
+
 pub fn init(cfg: &mut web::ServiceConfig) {
-    cfg.service(web::scope("/api/v1").service(health_check));
+    cfg.service(
+        web::scope("/api/v1")
+            .service(health_check)
+            .service(health_live)
+            .service(health_ready)
+            .service(list_users)
+            .service(create_user)
+            .service(monitor_status),
+    );
 }
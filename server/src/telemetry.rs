@@ -0,0 +1,14 @@
+use tracing_subscriber::EnvFilter;
+
+/// Initialize the global tracing subscriber with a JSON formatter suitable for
+/// log aggregation. The log level is read from `RUST_LOG`, defaulting to
+/// `info`.
+pub fn init_tracing() {
+    let env_filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+
+    tracing_subscriber::fmt()
+        .json()
+        .with_env_filter(env_filter)
+        .init();
+}